@@ -1,16 +1,20 @@
 #![allow(dead_code)]
 
 use std::{
-    sync::mpsc::{self, Receiver},
+    sync::{
+        Arc, RwLock,
+        mpsc::{self, Receiver},
+    },
     time::Duration,
+    thread::{self, JoinHandle},
     env,
     io,
-    path::PathBuf,
+    path::{Path, PathBuf},
     fmt::{self, Display, Formatter},
 };
 
 use serde::Deserialize;
-use notify::{RecommendedWatcher, Watcher, RecursiveMode, DebouncedEvent};
+use notify::{RecommendedWatcher, PollWatcher, Watcher, RecursiveMode, DebouncedEvent};
 
 use crate::{
     maths_utility::{Vec2, Rect, MinMax},
@@ -18,7 +22,11 @@ use crate::{
     bus::dbus::Notification,
 };
 
-static mut CONFIG: Option<Config> = None;
+// The live config, published behind an `RwLock<Option<Arc<Config>>>`.  Readers clone out the
+// inner `Arc` to get a cheap, self-contained snapshot, and `try_reload` swaps a freshly
+// validated `Config` in under the write lock.  A failed reload leaves the previous snapshot in
+// place, and there's no mutable global to race on, so none of this needs `unsafe`.
+static CONFIG: RwLock<Option<Arc<Config>>> = RwLock::new(None);
 
 #[derive(Debug)]
 pub enum Error {
@@ -59,8 +67,70 @@ impl Display for Error {
 }
 
 pub struct ConfigWatcher {
-    watcher: RecommendedWatcher,
+    watcher: WatcherKind,
+    // The canonicalized directory we asked the watcher to observe; kept so `shutdown` can
+    // unwatch exactly what `watch` registered.
+    path: PathBuf,
     pub receiver: Receiver<DebouncedEvent>,
+    // Handle for the forwarding thread, taken and joined by `shutdown`.
+    handle: Option<JoinHandle<()>>,
+}
+
+impl ConfigWatcher {
+    // Tear the watcher down cleanly.  We unwatch the path first so no new events are queued,
+    // then drop the watcher so its event sender disconnects; that unblocks the forwarding
+    // thread's blocking `recv` without any busy polling.  Joining the thread guarantees no
+    // stale `DebouncedEvent` reaches the main loop after reload or exit.
+    pub fn shutdown(mut self) {
+        if let Err(e) = self.watcher.unwatch(&self.path) {
+            println!("Couldn't cleanly unwatch the config path:\n\t{}", e);
+        }
+
+        drop(self.watcher);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+// Which file-watching backend to use.  `Native` relies on the platform's event-driven
+// mechanism (inotify on Linux) via `RecommendedWatcher`, while `Poll` repeatedly stats the
+// watched path on a timer.  Polling is the safe option on network filesystems and inside
+// containers where inotify events don't reliably propagate.
+#[derive(Debug, Deserialize, Clone)]
+pub enum WatchMethod {
+    Native,
+    Poll,
+}
+
+impl Default for WatchMethod {
+    fn default() -> Self {
+        WatchMethod::Native
+    }
+}
+
+// The concrete watcher backing a `ConfigWatcher`.  The two notify watchers are distinct
+// types (and `Watcher` isn't object-safe), so we wrap them in an enum rather than a trait
+// object and forward the handful of methods we need.
+enum WatcherKind {
+    Native(RecommendedWatcher),
+    Poll(PollWatcher),
+}
+
+impl WatcherKind {
+    fn watch(&mut self, path: &Path, mode: RecursiveMode) -> notify::Result<()> {
+        match self {
+            WatcherKind::Native(w) => w.watch(path, mode),
+            WatcherKind::Poll(w) => w.watch(path, mode),
+        }
+    }
+
+    fn unwatch(&mut self, path: &Path) -> notify::Result<()> {
+        match self {
+            WatcherKind::Native(w) => w.unwatch(path),
+            WatcherKind::Poll(w) => w.unwatch(path),
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -77,6 +147,11 @@ pub struct Config {
     pub timeout: i32,           // Default timeout.
     pub poll_interval: u64,
 
+    // Which backend to use when watching the config file for changes.  Defaults to `Native`
+    // for configs written before this field existed.
+    #[serde(default)]
+    pub watch_method: WatchMethod,
+
     // Draws rectangles around elements.
     pub debug: bool,
     pub debug_color: Color,
@@ -92,73 +167,121 @@ impl Config {
     // default config.
     // - If config was loaded successfully, then sets up a watcher on the config file to watch for changes,
     // and returns the watcher or None.
-    pub fn init() -> Option<ConfigWatcher> {
-        unsafe {
-            assert!(CONFIG.is_none());
-            let cfg_file = Config::installed_config();
-            match cfg_file {
-                Some(f) => {
-                    let cfg = Config::load(f.clone());
-                    match cfg {
-                        Ok(c) => CONFIG = Some(c),
-                        Err(e) => {
-                            println!("Found a config but couldn't load it, so will use default one for now:\n\t{}", e);
-                            CONFIG = Some(Config::default());
-                        }
-                    }
+    // Returns `Err` only when a config named explicitly (see `explicit_config`) can't be loaded;
+    // in that case there is no fallback to the default config.
+    pub fn init() -> Result<Option<ConfigWatcher>, Error> {
+        assert!(CONFIG.read().unwrap().is_none());
+
+        // An explicitly named config (via `WIRED_CONFIG` or `--config <path>`) has
+        // "must read" semantics: load it directly and propagate any error instead of
+        // falling back to the default, so a typo in the path is caught rather than
+        // silently ignored.  The implicit search below keeps its soft-fallback behavior.
+        if let Some(path) = Config::explicit_config() {
+            *CONFIG.write().unwrap() = Some(Arc::new(Config::load(path.clone())?));
+            return match Config::watch(path) {
+                Ok(w) => Ok(Some(w)),
+                Err(e) => {
+                    println!("There was a problem watching the config for changes; so won't watch:\n\t{}", e);
+                    Ok(None)
+                }
+            };
+        }
 
-                    // Watch the config file for changes, even if it didn't load correctly; we
-                    // assume that the config we found is the one we're using.
-                    // It would be nice to be able to watch the config directories for when a user
-                    // creates a config, but it seems impractical to watch that many directories.
-                    let watch = Config::watch(f);
-                    match watch {
-                        Ok(w) => return Some(w),
-                        Err(e) => {
-                            println!("There was a problem watching the config for changes; so won't watch:\n\t{}", e);
-                            return None;
-                        },
+        // If we can't find a config anywhere, write the embedded default to the preferred
+        // XDG location and use that, so new users get a real, editable file (and a useful
+        // watcher) rather than running the baked-in default forever.
+        let cfg_file = Config::installed_config().or_else(|| {
+            match Config::write_default() {
+                Ok(path) => {
+                    println!("No config found; wrote a default config to {}", path.display());
+                    Some(path)
+                }
+                Err(e) => {
+                    println!("No config found and couldn't create a default one:\n\t{}", e);
+                    None
+                }
+            }
+        });
+        match cfg_file {
+            Some(f) => {
+                match Config::load(f.clone()) {
+                    Ok(c) => *CONFIG.write().unwrap() = Some(Arc::new(c)),
+                    Err(e) => {
+                        println!("Found a config but couldn't load it, so will use default one for now:\n\t{}", e);
+                        *CONFIG.write().unwrap() = Some(Arc::new(Config::default()));
                     }
                 }
 
-                None => {
-                    println!("Couldn't load a config because we couldn't find one, so will use default.");
-                    CONFIG = Some(Config::default());
-                    return None;
-                },
-            };
-
-        }
-    }
+                // Watch the config file for changes, even if it didn't load correctly; we
+                // assume that the config we found is the one we're using.
+                // It would be nice to be able to watch the config directories for when a user
+                // creates a config, but it seems impractical to watch that many directories.
+                let watch = Config::watch(f);
+                match watch {
+                    Ok(w) => Ok(Some(w)),
+                    Err(e) => {
+                        println!("There was a problem watching the config for changes; so won't watch:\n\t{}", e);
+                        Ok(None)
+                    },
+                }
+            }
 
-    // Get immutable reference to global config variable.
-    pub fn get() -> &'static Config {
-        unsafe {
-            assert!(CONFIG.is_some());
-            // TODO: can as_ref be removed?
-            CONFIG.as_ref().unwrap()
+            None => {
+                println!("Couldn't find or create a config, so will use the built-in default.");
+                *CONFIG.write().unwrap() = Some(Arc::new(Config::default()));
+                Ok(None)
+            },
         }
     }
 
-    // Get mutable refernce to global config variable.
-    pub fn get_mut() -> &'static mut Config {
-        unsafe {
-            assert!(CONFIG.is_some());
-            // TODO: can as_ref be removed?
-            CONFIG.as_mut().unwrap()
-        }
+    // Get a cheap snapshot of the current config.  The returned `Arc` keeps this generation
+    // of the config alive for as long as the caller holds it, so a concurrent `try_reload`
+    // can publish a new one without tearing what this caller is already reading.
+    pub fn get() -> Arc<Config> {
+        CONFIG.read().unwrap().as_ref().expect("Config accessed before init()").clone()
     }
 
     // Attempt to load the config again.
-    // If we can, then replace the existing config.
-    // If we can't, then do nothing.
+    // If we can, then atomically publish it to all readers.
+    // If we can't, then leave the previous snapshot in place.
     pub fn try_reload(path: PathBuf) {
         match Config::load(path) {
-            Ok(cfg) => unsafe { CONFIG = Some(cfg) },
+            Ok(cfg) => *CONFIG.write().unwrap() = Some(Arc::new(cfg)),
             Err(e) => println!("Tried to reload the config but couldn't: {}", e),
         }
     }
 
+    // A config file named explicitly by the user, via the `WIRED_CONFIG` environment variable
+    // or a `--config <path>` command-line argument (the argument takes precedence).  Returning
+    // `Some` here bypasses the implicit search entirely; see `init` for the "must read"
+    // semantics that go with it.
+    fn explicit_config() -> Option<PathBuf> {
+        Config::resolve_explicit(env::args(), env::var("WIRED_CONFIG").ok())
+    }
+
+    // Resolve an explicitly-requested config path from the command-line arguments and the
+    // `WIRED_CONFIG` environment variable.  A `--config <path>` (or `--config=<path>`) argument
+    // takes precedence over the environment variable, and an empty variable counts as unset.
+    // Split out from `explicit_config` so the precedence rules can be unit-tested without
+    // touching the real process environment.
+    fn resolve_explicit<I: Iterator<Item = String>>(args: I, env: Option<String>) -> Option<PathBuf> {
+        let mut args = args;
+        while let Some(arg) = args.next() {
+            if arg == "--config" {
+                if let Some(path) = args.next() {
+                    return Some(PathBuf::from(path));
+                }
+            } else if let Some(path) = arg.strip_prefix("--config=") {
+                return Some(PathBuf::from(path));
+            }
+        }
+
+        match env {
+            Some(path) if !path.is_empty() => Some(PathBuf::from(path)),
+            _ => None,
+        }
+    }
+
     // https://github.com/alacritty/alacritty/blob/f14d24542c3ceda3b508c707eb79cf2fe2a04bd1/alacritty/src/config/mod.rs#L98
     fn installed_config() -> Option<PathBuf> {
         xdg::BaseDirectories::with_prefix("wired")
@@ -188,38 +311,149 @@ impl Config {
             })
     }
 
+    // Write the embedded default config to the preferred XDG config location, creating any
+    // missing parent directories, and return the path we wrote to.
+    fn write_default() -> Result<PathBuf, Error> {
+        let path = xdg::BaseDirectories::with_prefix("wired")
+            .map_err(|e| Error::Io(io::Error::new(io::ErrorKind::Other, e)))?
+            .place_config_file("wired.ron")
+            .map_err(Error::Io)?;
+
+        std::fs::write(&path, include_str!("../wired.ron")).map_err(Error::Io)?;
+        Ok(path)
+    }
+
     // Load config or return error.
+    //
+    // The base file is parsed first, then any `*.ron` fragments in a `wired.d/` directory
+    // sitting next to it are merged on top in lexicographic filename order (later files win).
+    // Because the `Config` fields aren't `Option`, we can't merge partial `Config`s directly;
+    // instead everything is merged at the `ron::Value` level and only turned into a `Config`
+    // once the fully merged document is assembled.
     pub fn load(path: PathBuf) -> Result<Self, Error> {
-        let cfg_string = std::fs::read_to_string(path);
-        let cfg_string = match cfg_string {
+        let base_string = match std::fs::read_to_string(&path) {
             Ok(string) => string,
             Err(e) => return Err(Error::Io(e)),
         };
 
-        let config: Result<Self, _> = ron::de::from_str(cfg_string.as_str());
-        match config {
-            Ok(cfg) => return cfg.validate(),
+        let mut merged: ron::Value = match ron::de::from_str(base_string.as_str()) {
+            Ok(value) => value,
             Err(e) => return Err(Error::Ron(e)),
         };
+
+        // Merge drop-in fragments from `wired.d/` next to the base config, if present.
+        // A missing directory is a no-op.
+        let dropin_dir = path.parent().unwrap_or_else(|| Path::new(".")).join("wired.d");
+        if dropin_dir.is_dir() {
+            let entries = match std::fs::read_dir(&dropin_dir) {
+                Ok(entries) => entries,
+                Err(e) => return Err(Error::Io(e)),
+            };
+
+            let mut fragments: Vec<PathBuf> = entries
+                .filter_map(|entry| entry.ok().map(|e| e.path()))
+                .filter(|p| p.extension().map_or(false, |ext| ext == "ron"))
+                .collect();
+            fragments.sort();
+
+            for fragment in fragments {
+                let frag_string = match std::fs::read_to_string(&fragment) {
+                    Ok(string) => string,
+                    Err(e) => return Err(Error::Io(e)),
+                };
+
+                let frag_value: ron::Value = match ron::de::from_str(frag_string.as_str()) {
+                    Ok(value) => value,
+                    Err(e) => {
+                        println!("Problem with config fragment {}:", fragment.display());
+                        return Err(Error::Ron(e));
+                    }
+                };
+
+                Config::merge_value(&mut merged, &frag_value);
+            }
+        }
+
+        let config: Result<Self, _> = merged.into_rust();
+        match config {
+            Ok(cfg) => cfg.validate(),
+            Err(e) => Err(Error::Ron(e)),
+        }
+    }
+
+    // Deep-merge `overlay` into `base` in place.  Two maps merge key-by-key (recursing into
+    // nested maps), while scalars and sequences from `overlay` replace whatever was in `base`.
+    fn merge_value(base: &mut ron::Value, overlay: &ron::Value) {
+        if let (ron::Value::Map(base_map), ron::Value::Map(overlay_map)) = (base, overlay) {
+            for (key, overlay_val) in overlay_map.iter() {
+                let merged_val = match base_map.get(key) {
+                    Some(existing @ ron::Value::Map(_)) if matches!(overlay_val, ron::Value::Map(_)) => {
+                        let mut existing = existing.clone();
+                        Config::merge_value(&mut existing, overlay_val);
+                        existing
+                    }
+                    _ => overlay_val.clone(),
+                };
+                base_map.insert(key.clone(), merged_val);
+            }
+        }
     }
 
     // Watch config file for changes, and send message to `Configwatcher` when something
     // happens.
     pub fn watch(mut path: PathBuf) -> Result<ConfigWatcher, Error> {
-        let (sender, receiver) = mpsc::channel();
-
-        // Duration is a debouncing period.
-        let mut watcher = notify::watcher(sender, Duration::from_millis(10))
-            .expect("Unable to spawn file watcher.");
-
-        // Watch dir.
+        // `sender`/`notify_rx` carry raw events from the notify watcher to our forwarding
+        // thread; `event_tx`/`receiver` re-expose those events to the main loop.  The extra
+        // hop is what lets us own a thread we can join on shutdown; dropping the watcher
+        // disconnects `sender`, which is how `shutdown` wakes the thread.
+        let (sender, notify_rx) = mpsc::channel();
+        let (event_tx, receiver) = mpsc::channel();
+
+        // Watch dir.  A bare relative path like `wired.ron` (e.g. from `WIRED_CONFIG=wired.ron`)
+        // has an empty parent after `pop`, so fall back to the current directory rather than
+        // canonicalizing `""` and panicking; any real failure is surfaced as an `Error`.
         path.pop();
-        let path = std::fs::canonicalize(path).expect("Couldn't canonicalize path, wtf.");
-        let result = watcher.watch(path, RecursiveMode::NonRecursive);
-        match result {
-            Ok(_) => return Ok(ConfigWatcher { watcher, receiver }),
-            Err(e) => return Err(Error::Watch(e)),
+        let dir = if path.as_os_str().is_empty() { PathBuf::from(".") } else { path };
+        let path = std::fs::canonicalize(&dir).map_err(Error::Io)?;
+
+        // Pick the backend according to the config.  `Native` uses an event-driven watcher
+        // with a short debounce period; if it can't initialize (e.g. inotify is unavailable)
+        // we fall back to polling rather than giving up on watching entirely.  `Poll` uses a
+        // `PollWatcher` whose interval is taken from `poll_interval`.
+        let poll_interval = Duration::from_millis(Config::get().poll_interval);
+        let mut watcher = match Config::get().watch_method {
+            WatchMethod::Native => match notify::watcher(sender.clone(), Duration::from_millis(10)) {
+                Ok(w) => WatcherKind::Native(w),
+                Err(e) => {
+                    println!("Couldn't initialize the native file watcher, falling back to polling:\n\t{}", e);
+                    match PollWatcher::new(sender, poll_interval) {
+                        Ok(w) => WatcherKind::Poll(w),
+                        Err(e) => return Err(Error::Watch(e)),
+                    }
+                }
+            },
+            WatchMethod::Poll => match PollWatcher::new(sender, poll_interval) {
+                Ok(w) => WatcherKind::Poll(w),
+                Err(e) => return Err(Error::Watch(e)),
+            },
         };
+
+        if let Err(e) = watcher.watch(&path, RecursiveMode::NonRecursive) {
+            return Err(Error::Watch(e));
+        }
+
+        // Block waiting for events and forward each to the main loop.  On shutdown the watcher
+        // is dropped, `sender` disconnects, and `recv` returns `Err`, ending the loop without
+        // any busy polling while the daemon is idle.
+        let handle = thread::spawn(move || {
+            while let Ok(event) = notify_rx.recv() {
+                if event_tx.send(event).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Ok(ConfigWatcher { watcher, path, receiver, handle: Some(handle) })
     }
 
     // Verify that the config is constructed correctly.
@@ -340,3 +574,170 @@ impl TextDimensionVariants {
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Mutex, atomic::{AtomicUsize, Ordering}};
+
+    // Tests that touch the global `CONFIG` serialize on this so they don't observe each
+    // other's writes while running in parallel.
+    static GLOBAL: Mutex<()> = Mutex::new(());
+
+    // A fresh, unique temp directory per call so tests can write their own base config and
+    // `wired.d/` fragments without stepping on each other when run in parallel.
+    fn temp_dir() -> PathBuf {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let dir = env::temp_dir().join(format!("wired-test-{}-{}", std::process::id(), n));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn value(s: &str) -> ron::Value {
+        ron::de::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn merge_later_map_wins_for_scalars() {
+        let mut base = value("(a: 1, b: 2)");
+        Config::merge_value(&mut base, &value("(b: 3)"));
+        assert_eq!(base, value("(a: 1, b: 3)"));
+    }
+
+    #[test]
+    fn merge_recurses_into_nested_maps() {
+        let mut base = value("(outer: (x: 1, y: 2))");
+        Config::merge_value(&mut base, &value("(outer: (y: 9, z: 3))"));
+        assert_eq!(base, value("(outer: (x: 1, y: 9, z: 3))"));
+    }
+
+    #[test]
+    fn merge_replaces_sequences_wholesale() {
+        let mut base = value("(xs: [1, 2, 3])");
+        Config::merge_value(&mut base, &value("(xs: [9])"));
+        assert_eq!(base, value("(xs: [9])"));
+    }
+
+    #[test]
+    fn load_default_survives_value_roundtrip() {
+        // The whole drop-in feature relies on the shipped default surviving
+        // `Value -> into_rust::<Config>()`, including the data-carrying `LayoutElement`
+        // enum nested in `layout`.  Loading it through `load()` must succeed and produce the
+        // exact same `Config` as a direct parse.  `Config` has no `PartialEq`, so we compare
+        // its `Debug` rendering, which is fully structural and catches any field that didn't
+        // round-trip.
+        let dir = temp_dir();
+        let base = dir.join("wired.ron");
+        std::fs::write(&base, include_str!("../wired.ron")).unwrap();
+
+        let via_load = Config::load(base).expect("default config must load via the merge path");
+        let direct: Config = ron::de::from_str(include_str!("../wired.ron")).unwrap();
+
+        assert_eq!(format!("{:?}", via_load), format!("{:?}", direct));
+    }
+
+    #[test]
+    fn dropin_fragment_overrides_base() {
+        let dir = temp_dir();
+        std::fs::write(dir.join("wired.ron"), include_str!("../wired.ron")).unwrap();
+
+        let dropin = dir.join("wired.d");
+        std::fs::create_dir_all(&dropin).unwrap();
+        std::fs::write(dropin.join("10-override.ron"), "(max_notifications: 99)").unwrap();
+
+        let cfg = Config::load(dir.join("wired.ron")).expect("base + fragment must load");
+        assert_eq!(cfg.max_notifications, 99);
+    }
+
+    #[test]
+    fn dropin_fragments_apply_in_lexicographic_order() {
+        let dir = temp_dir();
+        std::fs::write(dir.join("wired.ron"), include_str!("../wired.ron")).unwrap();
+
+        let dropin = dir.join("wired.d");
+        std::fs::create_dir_all(&dropin).unwrap();
+        std::fs::write(dropin.join("10-first.ron"), "(max_notifications: 1)").unwrap();
+        std::fs::write(dropin.join("20-second.ron"), "(max_notifications: 2)").unwrap();
+
+        let cfg = Config::load(dir.join("wired.ron")).unwrap();
+        assert_eq!(cfg.max_notifications, 2, "later fragment should win");
+    }
+
+    #[test]
+    fn bad_fragment_surfaces_as_ron_error() {
+        let dir = temp_dir();
+        std::fs::write(dir.join("wired.ron"), include_str!("../wired.ron")).unwrap();
+
+        let dropin = dir.join("wired.d");
+        std::fs::create_dir_all(&dropin).unwrap();
+        std::fs::write(dropin.join("broken.ron"), "(this is not valid ron").unwrap();
+
+        assert!(matches!(Config::load(dir.join("wired.ron")), Err(Error::Ron(_))));
+    }
+
+    #[test]
+    fn shutdown_joins_the_forwarding_thread() {
+        let _guard = GLOBAL.lock().unwrap();
+        *CONFIG.write().unwrap() = Some(Arc::new(Config::default()));
+
+        let dir = temp_dir();
+        let file = dir.join("wired.ron");
+        std::fs::write(&file, include_str!("../wired.ron")).unwrap();
+
+        let watcher = Config::watch(file).expect("watch should start");
+        // Returns promptly: dropping the watcher disconnects the channel and the thread joins.
+        watcher.shutdown();
+    }
+
+    fn args(list: &[&str]) -> std::vec::IntoIter<String> {
+        list.iter().map(|s| s.to_string()).collect::<Vec<_>>().into_iter()
+    }
+
+    #[test]
+    fn explicit_arg_takes_precedence_over_env() {
+        let got = Config::resolve_explicit(
+            args(&["wired", "--config", "/from/arg.ron"]),
+            Some("/from/env.ron".to_string()),
+        );
+        assert_eq!(got, Some(PathBuf::from("/from/arg.ron")));
+    }
+
+    #[test]
+    fn explicit_accepts_equals_form() {
+        let got = Config::resolve_explicit(args(&["wired", "--config=/from/arg.ron"]), None);
+        assert_eq!(got, Some(PathBuf::from("/from/arg.ron")));
+    }
+
+    #[test]
+    fn explicit_falls_back_to_env() {
+        let got = Config::resolve_explicit(args(&["wired"]), Some("/from/env.ron".to_string()));
+        assert_eq!(got, Some(PathBuf::from("/from/env.ron")));
+    }
+
+    #[test]
+    fn explicit_empty_env_counts_as_unset() {
+        assert_eq!(Config::resolve_explicit(args(&["wired"]), Some(String::new())), None);
+        assert_eq!(Config::resolve_explicit(args(&["wired"]), None), None);
+    }
+
+    #[test]
+    fn missing_explicit_config_is_a_hard_error() {
+        // Must-read semantics: loading a path that doesn't exist propagates an error rather
+        // than silently falling back to the default.
+        let missing = temp_dir().join("does-not-exist.ron");
+        assert!(matches!(Config::load(missing), Err(Error::Io(_))));
+    }
+
+    #[test]
+    fn failed_reload_keeps_previous_snapshot() {
+        let _guard = GLOBAL.lock().unwrap();
+        *CONFIG.write().unwrap() = Some(Arc::new(Config::default()));
+        let before = Config::get();
+
+        // Reloading from a path that can't be read/parsed must leave the live config intact.
+        Config::try_reload(temp_dir().join("does-not-exist.ron"));
+
+        assert!(Arc::ptr_eq(&before, &Config::get()));
+    }
+}